@@ -1,7 +1,10 @@
 use nu_engine::{eval_block, eval_expression};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Example, IntoPipelineData, PipelineData, Signature, Span, SyntaxShape, Value};
+use nu_protocol::{
+    BlockId, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Value, VarId,
+};
 
 #[derive(Clone)]
 pub struct For;
@@ -32,6 +35,11 @@ impl Command for For {
                 SyntaxShape::Block(Some(vec![])),
                 "the block to run",
             )
+            .switch(
+                "numbered",
+                "return a numbered item ($it.index and $it.item)",
+                Some('n'),
+            )
             .creates_scope()
     }
 
@@ -51,57 +59,49 @@ impl Command for For {
             .expect("internal error: missing keyword");
         let values = eval_expression(engine_state, stack, keyword_expr)?;
 
-        let block = call.positional[2]
+        let block_id = call.positional[2]
             .as_block()
             .expect("internal error: expected block");
 
+        let numbered = call.has_flag("numbered");
+        let head = call.head;
+
         let engine_state = engine_state.clone();
         let stack = stack.enter_scope();
 
         match values {
-            Value::List { vals, span } => Ok(vals
-                .into_iter()
-                .map(move |x| {
-                    let block = engine_state.get_block(block);
-
-                    let mut stack = stack.clone();
-                    stack.add_var(var_id, x);
-
-                    match eval_block(&engine_state, &mut stack, block, PipelineData::new()) {
-                        Ok(value) => Value::List {
-                            vals: value.collect(),
-                            span,
-                        },
-                        Err(error) => Value::Error { error },
-                    }
-                })
-                .into_pipeline_data()),
-            Value::Range { val, span } => Ok(val
-                .into_range_iter()?
-                .map(move |x| {
-                    let block = engine_state.get_block(block);
-
-                    let mut stack = stack.enter_scope();
-
-                    stack.add_var(var_id, x);
-
-                    match eval_block(&engine_state, &mut stack, block, PipelineData::new()) {
-                        Ok(value) => Value::List {
-                            vals: value.collect(),
-                            span,
-                        },
-                        Err(error) => Value::Error { error },
-                    }
-                })
-                .into_pipeline_data()),
+            Value::List { vals, .. } => {
+                let vals = number_if_requested(numbered, head, vals.into_iter());
+                Ok(run_each_iteration(
+                    engine_state,
+                    stack,
+                    var_id,
+                    block_id,
+                    vals,
+                ))
+            }
+            Value::Range { val, .. } => {
+                let vals = number_if_requested(numbered, head, val.into_range_iter()?);
+                Ok(run_each_iteration(
+                    engine_state,
+                    stack,
+                    var_id,
+                    block_id,
+                    vals,
+                ))
+            }
             x => {
-                let block = engine_state.get_block(block);
-
-                let mut stack = stack.enter_scope();
-
+                let mut stack = stack;
                 stack.add_var(var_id, x);
 
-                eval_block(&engine_state, &mut stack, block, PipelineData::new())
+                let block = engine_state.get_block(block_id);
+                match eval_block(&engine_state, &mut stack, block, PipelineData::new()) {
+                    Ok(value) => Ok(value),
+                    Err(ShellError::Break { .. }) | Err(ShellError::Continue { .. }) => {
+                        Ok(PipelineData::new())
+                    }
+                    Err(error) => Err(error),
+                }
             }
         }
     }
@@ -118,7 +118,7 @@ impl Command for For {
                         Value::Int { val: 4, span },
                         Value::Int { val: 9, span },
                     ],
-                    span: Span::unknown(),
+                    span,
                 }),
             },
             Example {
@@ -130,31 +130,109 @@ impl Command for For {
                         Value::Int { val: 2, span },
                         Value::Int { val: 3, span },
                     ],
-                    span: Span::unknown(),
+                    span,
+                }),
+            },
+            Example {
+                description: "Number each item and echo a message",
+                example: "for $it in ['bob' 'fred'] --numbered { $\"($it.index) is ($it.item)\" }",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::String {
+                            val: "0 is bob".into(),
+                            span,
+                        },
+                        Value::String {
+                            val: "1 is fred".into(),
+                            span,
+                        },
+                    ],
+                    span,
                 }),
             },
-            // FIXME? Numbered `for` is kinda strange, but was supported in previous nushell
-            // Example {
-            //     description: "Number each item and echo a message",
-            //     example: "for $it in ['bob' 'fred'] --numbered { $\"($it.index) is ($it.item)\" }",
-            //     result: Some(Value::List {
-            //         vals: vec![
-            //             Value::String {
-            //                 val: "0 is bob".into(),
-            //                 span,
-            //             },
-            //             Value::String {
-            //                 val: "0 is fred".into(),
-            //                 span,
-            //             },
-            //         ],
-            //         span: Span::unknown(),
-            //     }),
-            // },
         ]
     }
 }
 
+/// When `--numbered` is given, rebind each element to a `{index, item}`
+/// record with a zero-based index, instead of the bare element.
+fn number_if_requested(
+    numbered: bool,
+    span: Span,
+    values: impl Iterator<Item = Value> + 'static,
+) -> Box<dyn Iterator<Item = Value>> {
+    if numbered {
+        Box::new(values.enumerate().map(move |(index, item)| Value::Record {
+            cols: vec!["index".into(), "item".into()],
+            vals: vec![
+                Value::Int {
+                    val: index as i64,
+                    span,
+                },
+                item,
+            ],
+            span,
+        }))
+    } else {
+        Box::new(values)
+    }
+}
+
+/// Drive `values` one element at a time, binding each to `var_id` and running
+/// `block_id` against it, flattening each iteration's output into a single
+/// lazily-pulled stream instead of buffering every iteration up front. This
+/// lets `for` loop over infinite ranges or streaming external commands
+/// without unbounded memory growth, while still returning a normal
+/// `PipelineData` the caller can print, pipe into another command, or
+/// capture with `let` — exactly as `eval_block`'s result would be for any
+/// other command.
+///
+/// `break`/`continue` are consumed here: a `break` stops pulling from
+/// `values` (without unwinding past `for`), a `continue` skips to the next
+/// element, and any other error is surfaced as a `Value::Error` in the
+/// stream, matching how the rest of the pipeline reports per-item errors.
+fn run_each_iteration(
+    engine_state: EngineState,
+    stack: Stack,
+    var_id: VarId,
+    block_id: BlockId,
+    mut values: impl Iterator<Item = Value> + 'static,
+) -> PipelineData {
+    let mut current: Option<Box<dyn Iterator<Item = Value>>> = None;
+    let mut broken = false;
+
+    let stream = std::iter::from_fn(move || loop {
+        if let Some(cur) = current.as_mut() {
+            if let Some(value) = cur.next() {
+                return Some(value);
+            }
+            current = None;
+        }
+
+        if broken {
+            return None;
+        }
+
+        let x = values.next()?;
+
+        let block = engine_state.get_block(block_id);
+        let mut stack = stack.clone();
+        stack.add_var(var_id, x);
+
+        match eval_block(&engine_state, &mut stack, block, PipelineData::new()) {
+            Ok(pipeline_data) => current = Some(Box::new(pipeline_data.into_iter())),
+            Err(ShellError::Break { .. }) => broken = true,
+            Err(ShellError::Continue { .. }) => {}
+            Err(error) => {
+                broken = true;
+                current = Some(Box::new(std::iter::once(Value::Error { error })));
+            }
+        }
+    });
+
+    stream.into_pipeline_data()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -165,4 +243,61 @@ mod test {
 
         test_examples(For {})
     }
+
+    #[test]
+    fn numbered_wraps_each_item_with_a_correctly_incrementing_index() {
+        let span = Span::unknown();
+        let items = vec![
+            Value::String {
+                val: "bob".into(),
+                span,
+            },
+            Value::String {
+                val: "fred".into(),
+                span,
+            },
+        ];
+
+        let numbered: Vec<Value> =
+            number_if_requested(true, span, items.into_iter()).collect();
+
+        assert_eq!(
+            numbered,
+            vec![
+                Value::Record {
+                    cols: vec!["index".into(), "item".into()],
+                    vals: vec![
+                        Value::Int { val: 0, span },
+                        Value::String {
+                            val: "bob".into(),
+                            span,
+                        },
+                    ],
+                    span,
+                },
+                Value::Record {
+                    cols: vec!["index".into(), "item".into()],
+                    vals: vec![
+                        Value::Int { val: 1, span },
+                        Value::String {
+                            val: "fred".into(),
+                            span,
+                        },
+                    ],
+                    span,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn not_numbered_passes_items_through_unchanged() {
+        let span = Span::unknown();
+        let items = vec![Value::Int { val: 1, span }, Value::Int { val: 2, span }];
+
+        let passthrough: Vec<Value> =
+            number_if_requested(false, span, items.clone().into_iter()).collect();
+
+        assert_eq!(passthrough, items);
+    }
 }
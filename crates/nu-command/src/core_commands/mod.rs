@@ -0,0 +1,7 @@
+mod break_;
+mod continue_;
+mod for_;
+
+pub use break_::Break;
+pub use continue_::Continue;
+pub use for_::For;
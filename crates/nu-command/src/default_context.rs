@@ -0,0 +1,24 @@
+use nu_protocol::engine::{EngineState, StateWorkingSet};
+
+use crate::core_commands::{Break, Continue, For};
+
+/// Register this chunk's commands into `engine_state` so they can actually
+/// be found and run by name. Without this, a declared `Command` impl is dead
+/// code: the parser has nothing to resolve `break`/`continue`/`for` to.
+macro_rules! bind_command {
+    ( $engine_state:expr, $( $command:expr ),* $(,)? ) => {
+        let delta = {
+            let mut working_set = StateWorkingSet::new($engine_state);
+            $( working_set.add_decl(Box::new($command)); )*
+            working_set.render()
+        };
+
+        $engine_state
+            .merge_delta(delta)
+            .expect("internal error: failed to merge core command declarations");
+    };
+}
+
+pub fn add_core_commands(engine_state: &mut EngineState) {
+    bind_command!(engine_state, Break, Continue, For);
+}
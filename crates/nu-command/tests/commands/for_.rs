@@ -0,0 +1,61 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn break_stops_iterating_without_unwinding_past_the_loop() {
+    let actual = nu!(
+        cwd: ".",
+        pipeline(
+            r#"
+                for x in [1 2 3] {
+                    if $x == 2 {
+                        break
+                    }
+                    print $x
+                }
+                print "done"
+            "#
+        )
+    );
+
+    assert_eq!(actual.out, "1done");
+}
+
+#[test]
+fn continue_skips_only_the_current_iteration() {
+    let actual = nu!(
+        cwd: ".",
+        pipeline(
+            r#"
+                for x in [1 2 3] {
+                    if $x == 2 {
+                        continue
+                    }
+                    print $x
+                }
+            "#
+        )
+    );
+
+    assert_eq!(actual.out, "13");
+}
+
+#[test]
+fn break_in_an_inner_for_does_not_escape_the_outer_for() {
+    let actual = nu!(
+        cwd: ".",
+        pipeline(
+            r#"
+                for x in [1 2] {
+                    for y in [1 2 3] {
+                        if $y == 2 {
+                            break
+                        }
+                        print $y
+                    }
+                }
+            "#
+        )
+    );
+
+    assert_eq!(actual.out, "11");
+}
@@ -0,0 +1,54 @@
+use nu_protocol::ast::{Argument, Call};
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{PipelineData, ShellError, Span, Spanned};
+
+/// Assemble a [`Call`] to the command named `name` out of `arguments` and run
+/// it, without going through the parser.
+///
+/// This is the entry point for a Rust program embedding the engine that
+/// wants to invoke a command such as `for` programmatically: build up
+/// `arguments` with [`Argument::Positional`]/[`Argument::Named`] (using
+/// [`IntoExpression`](nu_protocol::IntoExpression) to turn plain Rust values
+/// into the `Expression`s they wrap), then call this function the same way
+/// the parser's `eval_call` would dispatch a parsed call.
+///
+/// `IntoExpression` only builds literal values (integers, strings, lists and
+/// ranges); the variable, `in` keyword and block positionals that `for`
+/// takes are not produced by it and have to be built as `Expression`s
+/// directly.
+pub fn call_command(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    name: &str,
+    arguments: Vec<Argument>,
+    head: Span,
+) -> Result<PipelineData, ShellError> {
+    let decl_id = engine_state.find_decl(name.as_bytes(), &[]).ok_or_else(|| {
+        ShellError::GenericError(
+            format!("command `{name}` not found"),
+            "no command with this name is registered".into(),
+            Some(head),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    let mut call = Call::new(head);
+    call.decl_id = decl_id;
+
+    for argument in arguments {
+        match argument {
+            Argument::Positional(expr) => call.positional.push(expr),
+            Argument::Named(name, expr) => call.named.push((
+                Spanned {
+                    item: name,
+                    span: head,
+                },
+                expr,
+            )),
+        }
+    }
+
+    let decl = engine_state.get_decl(decl_id);
+    decl.run(engine_state, stack, &call, PipelineData::new())
+}
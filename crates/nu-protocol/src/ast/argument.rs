@@ -0,0 +1,18 @@
+use super::Expression;
+
+/// One argument to a [`Call`](super::Call) being assembled by hand, as an
+/// embedding host does, rather than produced by parsing a script.
+///
+/// A `Vec<Argument>` plays the role that the parser normally plays when it
+/// turns a command invocation into a `Call`'s `positional`/`named` fields.
+/// See [`IntoExpression`](crate::IntoExpression) for building the
+/// `Expression`s these wrap out of plain Rust values.
+#[derive(Debug, Clone)]
+pub enum Argument {
+    /// A positional argument, e.g. the `in 1..3` in `for x in 1..3 { .. }`,
+    /// which `for` takes as a keyword expression wrapping the range.
+    Positional(Expression),
+    /// A named argument (long flag). `None` means the flag was passed bare,
+    /// with no value, e.g. `--numbered`.
+    Named(String, Option<Expression>),
+}
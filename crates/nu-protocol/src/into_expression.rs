@@ -0,0 +1,106 @@
+use crate::ast::{Expr, Expression, RangeInclusion, RangeOperator};
+use crate::{Span, Type};
+
+/// Convert an ordinary Rust value into the [`Expression`] an embedding host
+/// needs in order to assemble a [`Call`](crate::ast::Call) by hand, via
+/// [`Argument`](crate::ast::Argument), instead of going through the parser.
+///
+/// Each impl produces the same `Expression` shape the parser would have
+/// produced for the equivalent literal, so commands see no difference
+/// between a host-built `Call` and a parsed one.
+pub trait IntoExpression {
+    fn into_expression(self, span: Span) -> Expression;
+}
+
+impl IntoExpression for i64 {
+    fn into_expression(self, span: Span) -> Expression {
+        Expression {
+            expr: Expr::Int(self),
+            span,
+            ty: Type::Int,
+            custom_completion: None,
+        }
+    }
+}
+
+impl IntoExpression for String {
+    fn into_expression(self, span: Span) -> Expression {
+        Expression {
+            expr: Expr::String(self),
+            span,
+            ty: Type::String,
+            custom_completion: None,
+        }
+    }
+}
+
+impl IntoExpression for &str {
+    fn into_expression(self, span: Span) -> Expression {
+        self.to_string().into_expression(span)
+    }
+}
+
+impl<T> IntoExpression for Vec<T>
+where
+    T: IntoExpression,
+{
+    fn into_expression(self, span: Span) -> Expression {
+        let items = self
+            .into_iter()
+            .map(|item| item.into_expression(span))
+            .collect();
+
+        Expression {
+            expr: Expr::List(items),
+            span,
+            ty: Type::List(Box::new(Type::Any)),
+            custom_completion: None,
+        }
+    }
+}
+
+impl IntoExpression for std::ops::Range<i64> {
+    fn into_expression(self, span: Span) -> Expression {
+        let from = self.start.into_expression(span);
+        let to = self.end.into_expression(span);
+
+        Expression {
+            expr: Expr::Range(
+                Some(Box::new(from)),
+                None,
+                Some(Box::new(to)),
+                RangeOperator {
+                    inclusion: RangeInclusion::RightExclusive,
+                    span,
+                    next_op_span: span,
+                },
+            ),
+            span,
+            ty: Type::Range,
+            custom_completion: None,
+        }
+    }
+}
+
+impl IntoExpression for std::ops::RangeInclusive<i64> {
+    fn into_expression(self, span: Span) -> Expression {
+        let from = (*self.start()).into_expression(span);
+        let to = (*self.end()).into_expression(span);
+
+        Expression {
+            expr: Expr::Range(
+                Some(Box::new(from)),
+                None,
+                Some(Box::new(to)),
+                RangeOperator {
+                    inclusion: RangeInclusion::Inclusive,
+                    span,
+                    next_op_span: span,
+                },
+            ),
+            span,
+            ty: Type::Range,
+            custom_completion: None,
+        }
+    }
+}